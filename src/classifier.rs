@@ -1,63 +1,116 @@
-pub const COMMA_CLASS: u8 = 1;
+pub const DELIMITER_CLASS: u8 = 1;
 pub const NEW_LINE_CLASS: u8 = 2;
-
 pub const QUOTATION_CLASS: u8 = 3;
+pub const COMMENT_CLASS: u8 = 4;
+pub const ESCAPE_CLASS: u8 = 5;
 
-pub const LO_LOOKUP: [u8; 16] = [
-    0,
-    0,
-    QUOTATION_CLASS,
-    0,
-    0,
-    0,
-    0,
-    0,
-    0,
-    0,
-    NEW_LINE_CLASS,
-    0,
-    COMMA_CLASS,
-    NEW_LINE_CLASS,
-    0,
-    0,
-];
-pub const HI_LOOKUP: [u8; 16] = [
-    NEW_LINE_CLASS,
-    0,
-    COMMA_CLASS | QUOTATION_CLASS,
-    0,
-    0,
-    0,
-    0,
-    0,
-    0,
-    0,
-    0,
-    0,
-    0,
-    0,
-    0,
-    0,
-];
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
 
 use crate::u8x16::u8x16;
 
+/// Describes which bytes carry structural meaning in the input: the field
+/// delimiter, the quote character, and an optional line-comment marker.
+/// Everything else in the classifier is derived from this at runtime, so
+/// swapping the dialect is enough to scan TSV, semicolon, or pipe-delimited
+/// files instead of RFC 4180 comma CSV.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Dialect {
+    pub delimiter: u8,
+    pub quote: u8,
+    pub comment: Option<u8>,
+    pub escape: Option<u8>,
+}
+
+impl Dialect {
+    pub const fn new(delimiter: u8, quote: u8) -> Self {
+        Self {
+            delimiter,
+            quote,
+            comment: None,
+            escape: None,
+        }
+    }
+
+    /// Any row whose first byte is `comment` is dropped entirely by
+    /// [`crate::reader::CsvReader::read`], the common "whole line is a
+    /// comment" convention.
+    pub const fn with_comment(mut self, comment: u8) -> Self {
+        self.comment = Some(comment);
+        self
+    }
+
+    /// Switches quote escaping from doubled quotes (`""`) to a leading
+    /// escape byte (`\"`), the convention many non-RFC-4180 exporters use.
+    pub const fn with_escape(mut self, escape: u8) -> Self {
+        self.escape = Some(escape);
+        self
+    }
+}
+
+impl Default for Dialect {
+    /// RFC 4180: comma-delimited, double-quoted.
+    fn default() -> Self {
+        Self::new(b',', b'"')
+    }
+}
+
+/// Builds the low/high nibble lookup tables `u8x16::classify` expects by
+/// OR-ing each dialect byte's class into the slot addressed by its nibble,
+/// the same layout the hardcoded RFC 4180 tables used to hand-encode.
+///
+/// `pub(crate)` so [`crate::reader::StreamingCsvReader`] can build the same
+/// tables without going through a [`CsvClassifier`].
+pub(crate) fn build_lookup_tables(dialect: &Dialect) -> ([u8; 16], [u8; 16]) {
+    let mut lo_lookup = [0u8; 16];
+    let mut hi_lookup = [0u8; 16];
+
+    let mut mark = |byte: u8, class: u8| {
+        lo_lookup[(byte & 0x0F) as usize] |= class;
+        hi_lookup[(byte >> 4) as usize] |= class;
+    };
+
+    mark(b'\n', NEW_LINE_CLASS);
+    mark(b'\r', NEW_LINE_CLASS);
+    mark(dialect.delimiter, DELIMITER_CLASS);
+    mark(dialect.quote, QUOTATION_CLASS);
+
+    if let Some(comment) = dialect.comment {
+        mark(comment, COMMENT_CLASS);
+    }
+
+    if let Some(escape) = dialect.escape {
+        mark(escape, ESCAPE_CLASS);
+    }
+
+    (lo_lookup, hi_lookup)
+}
+
 #[derive(Debug)]
 pub struct CsvClassifier<'a> {
     cursor: usize,
     data: &'a [u8],
+    lo_lookup: [u8; 16],
+    hi_lookup: [u8; 16],
 }
 
 impl<'a> CsvClassifier<'a> {
-    pub const fn new(data: &'a [u8]) -> Self {
-        Self { cursor: 0, data }
+    pub fn new(data: &'a [u8], dialect: Dialect) -> Self {
+        let (lo_lookup, hi_lookup) = build_lookup_tables(&dialect);
+
+        Self {
+            cursor: 0,
+            data,
+            lo_lookup,
+            hi_lookup,
+        }
     }
 
     pub fn classify(&mut self) -> Vec<u8x16> {
         let mut bitsets = Vec::new();
 
-        let high_nibble_lookup = u8x16::from_slice_unchecked(&HI_LOOKUP);
-        let low_nibble_lookup = u8x16::from_slice_unchecked(&LO_LOOKUP);
+        let high_nibble_lookup = u8x16::from_slice_unchecked(&self.hi_lookup);
+        let low_nibble_lookup = u8x16::from_slice_unchecked(&self.lo_lookup);
 
         while self.cursor < self.data.len() {
             let (lanes, _aligned) = self.load_u8x16();
@@ -87,7 +140,11 @@ impl<'a> CsvClassifier<'a> {
 
         let last = self.data[self.data.len() - 1];
 
-        if last != 0x0A && last != 0x0D {
+        // A full 16-byte tail with no trailing newline has nowhere left in
+        // `temp` to append the synthetic one, and the cursor is already at
+        // the end of the data, so no newline is added for it at all; the
+        // reader flushes that last unterminated field itself.
+        if last != 0x0A && last != 0x0D && slice.len() < 16 {
             temp[slice.len()] = 0x0A;
         }
 
@@ -95,13 +152,105 @@ impl<'a> CsvClassifier<'a> {
     }
 }
 
+/// The most distinct bytes a single [`ByteSet`] can recognize. Membership
+/// has to survive an AND of an independently-computed low-nibble class and
+/// high-nibble class, so (unlike [`CsvClassifier`], which only ever ANDs a
+/// single dialect byte's own nibbles against themselves) two different
+/// member bytes can't be allowed to share a class bit -- otherwise byte A's
+/// low nibble and byte B's high nibble can combine into a class that matches
+/// some third byte nobody registered. Giving every byte its own bit sidesteps
+/// that, and a `u8` class only has 8 bits to spare.
+pub const BYTE_SET_CAPACITY: usize = 8;
+
+/// An arbitrary set of up to [`BYTE_SET_CAPACITY`] "interesting" bytes,
+/// compiled into the same low/high nibble lookup tables [`CsvClassifier`]
+/// builds for its CSV-specific classes, but for standalone membership
+/// testing. Useful for a fast SIMD byteset scan -- counting newlines,
+/// locating a sentinel -- without committing to CSV semantics.
+#[derive(Debug, Clone, Copy)]
+pub struct ByteSet {
+    lo_lookup: [u8; 16],
+    hi_lookup: [u8; 16],
+}
+
+impl ByteSet {
+    /// Builds a set recognizing every byte in `bytes`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bytes` has more than [`BYTE_SET_CAPACITY`] entries.
+    pub fn new(bytes: &[u8]) -> Self {
+        assert!(
+            bytes.len() <= BYTE_SET_CAPACITY,
+            "ByteSet supports at most {BYTE_SET_CAPACITY} distinct bytes, got {}",
+            bytes.len()
+        );
+
+        let mut lo_lookup = [0u8; 16];
+        let mut hi_lookup = [0u8; 16];
+
+        for (i, &byte) in bytes.iter().enumerate() {
+            let bit = 1u8 << i;
+            lo_lookup[(byte & 0x0F) as usize] |= bit;
+            hi_lookup[(byte >> 4) as usize] |= bit;
+        }
+
+        Self {
+            lo_lookup,
+            hi_lookup,
+        }
+    }
+
+    /// Scans `data`, returning one bitset per 64-byte block (the trailing,
+    /// partial block is zero-padded) with a `1` bit for every member byte --
+    /// big-endian like [`CsvClassifier`]'s bitsets, so bit 63 is a block's
+    /// first byte.
+    pub fn scan(&self, data: &[u8]) -> Vec<u64> {
+        let high_nibble_lookup = u8x16::from_slice_unchecked(&self.hi_lookup);
+        let low_nibble_lookup = u8x16::from_slice_unchecked(&self.lo_lookup);
+        let zero = u8x16::broadcast(0);
+
+        let vectors: Vec<u8x16> = data
+            .chunks(u8x16::LANE_COUNT)
+            .map(|chunk| {
+                let lane = if chunk.len() == u8x16::LANE_COUNT {
+                    u8x16::from_slice_unchecked(chunk)
+                } else {
+                    let mut temp = [0u8; 16];
+                    temp[..chunk.len()].copy_from_slice(chunk);
+                    u8x16::from_slice_unchecked(&temp)
+                };
+
+                let (hi, lo) = lane.nibbles();
+                high_nibble_lookup.classify(hi) & low_nibble_lookup.classify(lo)
+            })
+            .collect();
+
+        vectors
+            .chunks(4)
+            .map(|chunk| {
+                let mut packed = 0u64;
+                for (i, &v) in chunk.iter().enumerate() {
+                    // A byte is a member iff its two matched bits share at
+                    // least one position, i.e. the AND above is non-zero --
+                    // there's no single broadcast value every member byte
+                    // produces now that each one gets its own bit.
+                    let word = !v.eq(zero).bitset() as u64;
+                    packed |= word << (48 - i * 16);
+                }
+                packed
+            })
+            .collect()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_basic_classify() {
-        let mut classifier = CsvClassifier::new(b"a,b,c\nf,\"g\"");
+        let mut classifier = CsvClassifier::new(b"a,b,c\nf,\"g\"", Dialect::default());
         let bitsets = classifier.classify();
 
         assert_eq!(bitsets.len(), 1);
@@ -111,13 +260,13 @@ mod tests {
             res,
             [
                 0,
-                COMMA_CLASS,
+                DELIMITER_CLASS,
                 0,
-                COMMA_CLASS,
+                DELIMITER_CLASS,
                 0,
                 NEW_LINE_CLASS,
                 0,
-                COMMA_CLASS,
+                DELIMITER_CLASS,
                 QUOTATION_CLASS,
                 0,
                 QUOTATION_CLASS,
@@ -129,4 +278,106 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn test_classify_tsv_dialect() {
+        let dialect = Dialect::new(b'\t', b'\'');
+        let mut classifier = CsvClassifier::new(b"a\tb\tc\nf\t'g'", dialect);
+        let bitsets = classifier.classify();
+
+        assert_eq!(bitsets.len(), 1);
+        let res: [u8; 16] = bitsets[0].into();
+
+        assert_eq!(
+            res,
+            [
+                0,
+                DELIMITER_CLASS,
+                0,
+                DELIMITER_CLASS,
+                0,
+                NEW_LINE_CLASS,
+                0,
+                DELIMITER_CLASS,
+                QUOTATION_CLASS,
+                0,
+                QUOTATION_CLASS,
+                NEW_LINE_CLASS,
+                0,
+                0,
+                0,
+                0,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_byteset_scan() {
+        let set = ByteSet::new(b"\n");
+        let bitsets = set.scan(b"a\nbc\n");
+
+        assert_eq!(bitsets.len(), 1);
+        assert_eq!(bitsets[0].count_ones(), 2);
+        // bit 63 is byte 0; the newlines are at bytes 1 and 4.
+        assert_eq!(bitsets[0] & (1 << (63 - 1)), 1 << (63 - 1));
+        assert_eq!(bitsets[0] & (1 << (63 - 4)), 1 << (63 - 4));
+    }
+
+    #[test]
+    fn test_byteset_no_matches() {
+        let set = ByteSet::new(b"xyz");
+        let bitsets = set.scan(b"abc");
+
+        assert_eq!(bitsets, vec![0]);
+    }
+
+    #[test]
+    fn test_byteset_doesnt_cross_nibbles_between_members() {
+        // 0x0A and 0x2C share no nibble with each other, but 0x0A's high
+        // nibble (0x0) and 0x2C's low nibble (0xC) combine into 0x0C, and
+        // 0x2C's high nibble with 0x0A's low nibble into 0x2A, bytes neither
+        // one is. A single shared class bit used to report them as false
+        // positives.
+        let set = ByteSet::new(b"\n,"); // 0x0A, 0x2C
+        let bitsets = set.scan(&[0x0C, 0x2A]);
+
+        assert_eq!(bitsets, vec![0]);
+    }
+
+    #[test]
+    fn test_byteset_doesnt_cross_nibbles_between_members_2() {
+        // ',' is 0x2C and ';' is 0x3B; '+' (0x2B) and '<' (0x3C) combine
+        // their nibbles the same ghost-match way.
+        let set = ByteSet::new(b",;");
+        let bitsets = set.scan(b"+<");
+
+        assert_eq!(bitsets, vec![0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_byteset_rejects_too_many_bytes() {
+        ByteSet::new(&[0, 1, 2, 3, 4, 5, 6, 7, 8]);
+    }
+
+    #[test]
+    fn test_classify_tail_exactly_one_lane_without_trailing_newline() {
+        // A tail that exactly fills the last 16-byte lane, with no real
+        // newline, used to panic trying to append a synthetic one past the
+        // lane's end.
+        let data = b"aaaaaaaaaaaaaaaa";
+        assert_eq!(data.len(), 16);
+
+        let mut classifier = CsvClassifier::new(data, Dialect::default());
+        classifier.classify();
+    }
+
+    #[test]
+    fn test_classify_tail_spans_multiple_lanes_without_trailing_newline() {
+        let data = b"aaaaaaaaaaaaaaaabbbbbbbbbbbbbbbb";
+        assert_eq!(data.len(), 32);
+
+        let mut classifier = CsvClassifier::new(data, Dialect::default());
+        classifier.classify();
+    }
 }
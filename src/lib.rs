@@ -0,0 +1,7 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub mod classifier;
+pub mod reader;
+pub mod u8x16;
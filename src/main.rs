@@ -1,4 +1,5 @@
 use anyhow::anyhow;
+use simdcsv::classifier::Dialect;
 use simdcsv::reader::CsvReader;
 
 fn main() -> anyhow::Result<()> {
@@ -6,7 +7,7 @@ fn main() -> anyhow::Result<()> {
 
     let data = args.next().ok_or_else(|| anyhow!("No argument passed"))?;
 
-    let mut reader = CsvReader::new(data.as_bytes());
+    let mut reader = CsvReader::new(data.as_bytes(), Dialect::default());
     let rows = reader.read();
 
     println!("{:?}", rows);
@@ -1,73 +1,103 @@
-use crate::classifier::CsvClassifier;
-use crate::classifier::{COMMA_CLASS, NEW_LINE_CLASS, QUOTATION_CLASS};
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use crate::classifier::{CsvClassifier, Dialect};
+use crate::classifier::{DELIMITER_CLASS, ESCAPE_CLASS, NEW_LINE_CLASS, QUOTATION_CLASS};
 use crate::u8x16::u8x16;
-use std::ops::Range;
+use core::ops::Range;
 
 pub type FieldRef = Range<usize>;
 pub type RowRef = Vec<FieldRef>;
 
 /// [`CsvReader`] holds 3 bits per character in the data set.
-/// To understand csv, you only need to know whether a byte is a quotation, comma, new line delimiter, or something else.
+/// To understand csv, you only need to know whether a byte is a quotation, delimiter, new line delimiter, or something else.
 #[derive(Debug)]
-pub struct CsvReader {
+pub struct CsvReader<'a> {
+    data: &'a [u8],
+    dialect: Dialect,
     quotation_bitsets: Vec<u64>,
-    comma_bitsets: Vec<u64>,
+    delimiter_bitsets: Vec<u64>,
     new_line_bitsets: Vec<u64>,
+    escape_bitsets: Option<Vec<u64>>,
 }
 
-impl CsvReader {
-    pub fn new(data: &[u8]) -> Self {
+impl<'a> CsvReader<'a> {
+    pub fn new(data: &'a [u8], dialect: Dialect) -> Self {
         // todo: can you store non-utf8 encoded characters in csv?
 
-        let vectors = CsvClassifier::new(data).classify();
-        let capacity = vectors.len() / 4 + (vectors.len() % 4 != 0) as usize;
+        let vectors = CsvClassifier::new(data, dialect).classify();
+        let capacity = vectors.len() / 4 + !vectors.len().is_multiple_of(4) as usize;
 
-        let comma_broadcast = u8x16::broadcast(COMMA_CLASS);
+        let delimiter_broadcast = u8x16::broadcast(DELIMITER_CLASS);
         let new_line_broadcast = u8x16::broadcast(NEW_LINE_CLASS);
         let quotation_broadcast = u8x16::broadcast(QUOTATION_CLASS);
 
-        let mut comma_bitsets = Vec::with_capacity(capacity);
+        let mut delimiter_bitsets = Vec::with_capacity(capacity);
         let mut new_line_bitsets = Vec::with_capacity(capacity);
         let mut quotation_bitsets = Vec::with_capacity(capacity);
 
         vectors.chunks(4).for_each(|chunk| {
-            comma_bitsets.push(build_u64(chunk, comma_broadcast));
+            delimiter_bitsets.push(build_u64(chunk, delimiter_broadcast));
             new_line_bitsets.push(build_u64(chunk, new_line_broadcast));
             quotation_bitsets.push(build_u64(chunk, quotation_broadcast));
         });
 
+        let escape_bitsets = dialect.escape.map(|_| {
+            let escape_broadcast = u8x16::broadcast(ESCAPE_CLASS);
+            vectors
+                .chunks(4)
+                .map(|chunk| build_u64(chunk, escape_broadcast))
+                .collect()
+        });
+
         Self {
-            comma_bitsets,
+            data,
+            dialect,
+            delimiter_bitsets,
             new_line_bitsets,
             quotation_bitsets,
+            escape_bitsets,
         }
     }
+
     pub fn read(&mut self) -> Vec<RowRef> {
         let mut rows = Vec::new();
         let mut current_row = Vec::new();
 
         let mut start = 0;
         let mut end = 0;
+        let mut escape_carry = false;
 
         for i in 0..self.quotation_bitsets.len() {
-            let valid_quotations = remove_escaped_quotations(self.quotation_bitsets[i]);
+            let valid_quotations = match &self.escape_bitsets {
+                Some(escape_bitsets) => {
+                    let (valid, next_carry) = mask_backslash_escaped_quotes_with_carry(
+                        self.quotation_bitsets[i],
+                        escape_bitsets[i],
+                        escape_carry,
+                    );
+                    escape_carry = next_carry;
+                    valid
+                }
+                None => remove_escaped_quotations(self.quotation_bitsets[i]),
+            };
             let outside_quotations = !mark_inside_quotations(valid_quotations);
 
-            let mut valid_commas = self.comma_bitsets[i] & outside_quotations;
+            let mut valid_delimiters = self.delimiter_bitsets[i] & outside_quotations;
             let mut valid_new_line = self.new_line_bitsets[i] & outside_quotations;
 
             // no structual characters exist in this bitset,
             // so we can just advance the end cursor
-            if valid_commas == 0 && valid_new_line == 0 {
+            if valid_delimiters == 0 && valid_new_line == 0 {
                 end += 64;
                 continue;
             }
 
             loop {
-                let first_comma = valid_commas.leading_zeros() as usize;
+                let first_delimiter = valid_delimiters.leading_zeros() as usize;
                 let first_new_line = valid_new_line.leading_zeros() as usize;
 
-                let bits_traveled = first_comma.min(first_new_line);
+                let bits_traveled = first_delimiter.min(first_new_line);
 
                 // there aren't any more structual characters to consider
                 // so we just advance the end cursor to the next bitset
@@ -78,10 +108,24 @@ impl CsvReader {
 
                 end += bits_traveled;
 
-                if start < end {
+                let is_newline = first_new_line < first_delimiter;
+                // A zero-width gap right after a `\r` that's itself closing
+                // on a `\n` is just the second half of a CRLF terminator, not
+                // a genuine empty trailing field -- skip it. Any other
+                // zero-width gap (e.g. `a,,b`) is a real empty field, so it's
+                // still recorded, keeping column positions aligned with the
+                // schema a caller matches against.
+                let is_crlf_tail = end == start
+                    && is_newline
+                    && end > 0
+                    && end < self.data.len()
+                    && self.data[end] == b'\n'
+                    && self.data[end - 1] == b'\r';
+
+                if !is_crlf_tail {
                     current_row.push(Range { start, end });
 
-                    if first_new_line < first_comma {
+                    if is_newline {
                         rows.push(current_row.clone());
                         current_row.clear();
                     }
@@ -90,15 +134,164 @@ impl CsvReader {
                 // consume the structual character
                 end += 1;
 
-                valid_commas <<= bits_traveled + 1;
+                valid_delimiters <<= bits_traveled + 1;
                 valid_new_line <<= bits_traveled + 1;
 
                 start = end;
             }
         }
 
+        // A trailing field with no terminator after it (e.g. the input's
+        // length is an exact multiple of 16, so the classifier didn't need
+        // to synthesize a trailing newline) never triggers the push inside
+        // the loop above -- flush whatever's left, same as
+        // `StreamingCsvReader::finish` does for its own last partial block.
+        if start < self.data.len() {
+            current_row.push(Range {
+                start,
+                end: self.data.len(),
+            });
+        }
+
+        if !current_row.is_empty() {
+            rows.push(current_row);
+        }
+
+        if let Some(comment) = self.dialect.comment {
+            rows.retain(|row| match row.first() {
+                Some(field) => self.data[field.start] != comment,
+                None => true,
+            });
+        }
+
         rows
     }
+
+    /// Parses each field straight into a [`Cell`] according to `schema`,
+    /// reusing the [`FieldRef`] ranges from [`Self::read`] instead of
+    /// handing the caller raw byte slices to coerce themselves. A column
+    /// past the end of `schema` is treated as [`Tag::Str`]. Fails with the
+    /// offending row/column on the first field that doesn't match its tag.
+    pub fn read_typed(&mut self, schema: &[Tag]) -> Result<Vec<Vec<Cell>>, TypedReadError> {
+        self.read()
+            .iter()
+            .enumerate()
+            .map(|(row, fields)| {
+                fields
+                    .iter()
+                    .enumerate()
+                    .map(|(column, field)| {
+                        let tag = schema.get(column).copied().unwrap_or(Tag::Str);
+
+                        parse_cell(self.data, field.clone(), tag, self.dialect.quote)
+                            .ok_or(TypedReadError { row, column })
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+/// An expected field type for [`CsvReader::read_typed`]'s per-column schema.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tag {
+    Int,
+    Float,
+    Bool,
+    Str,
+}
+
+/// A field parsed according to its [`Tag`]. `Str` owns its bytes (with
+/// surrounding quotes stripped and doubled-`""` escapes collapsed to a
+/// single `"`), since collapsing can shrink the field and there's no longer
+/// a contiguous range of the source to borrow.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Cell {
+    Int(i64),
+    Float(f64),
+    Bool(bool),
+    Str(Vec<u8>),
+    Null,
+}
+
+/// The field at `row`/`column` didn't parse as its schema [`Tag`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TypedReadError {
+    pub row: usize,
+    pub column: usize,
+}
+
+impl core::fmt::Display for TypedReadError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "field at row {}, column {} did not match its schema type",
+            self.row, self.column
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TypedReadError {}
+
+/// Strips a surrounding pair of `quote` bytes from `range`, if present.
+fn unquoted_range(data: &[u8], range: FieldRef, quote: u8) -> FieldRef {
+    let raw = &data[range.clone()];
+
+    if raw.len() >= 2 && raw[0] == quote && raw[raw.len() - 1] == quote {
+        range.start + 1..range.end - 1
+    } else {
+        range
+    }
+}
+
+fn parse_cell(data: &[u8], range: FieldRef, tag: Tag, quote: u8) -> Option<Cell> {
+    if range.is_empty() {
+        return Some(Cell::Null);
+    }
+
+    let range = unquoted_range(data, range, quote);
+
+    if tag == Tag::Str {
+        return Some(Cell::Str(collapse_doubled_quotes(&data[range], quote)));
+    }
+
+    let text = core::str::from_utf8(&data[range]).ok()?;
+
+    match tag {
+        Tag::Int => text.parse::<i64>().ok().map(Cell::Int),
+        Tag::Float => text.parse::<f64>().ok().map(Cell::Float),
+        Tag::Bool => {
+            if text.eq_ignore_ascii_case("true") {
+                Some(Cell::Bool(true))
+            } else if text.eq_ignore_ascii_case("false") {
+                Some(Cell::Bool(false))
+            } else {
+                None
+            }
+        }
+        Tag::Str => unreachable!(),
+    }
+}
+
+/// Collapses RFC 4180 doubled-quote escapes (`""` -> `"`) in an already
+/// unquoted field, the same convention [`remove_escaped_quotations`] decodes
+/// at the bitset level for the field-splitting pass itself.
+fn collapse_doubled_quotes(field: &[u8], quote: u8) -> Vec<u8> {
+    let mut out = Vec::with_capacity(field.len());
+
+    let mut i = 0;
+    while i < field.len() {
+        out.push(field[i]);
+
+        if field[i] == quote && field.get(i + 1) == Some(&quote) {
+            i += 2;
+        } else {
+            i += 1;
+        }
+    }
+
+    out
 }
 
 const fn remove_escaped_quotations(q: u64) -> u64 {
@@ -108,6 +301,54 @@ const fn remove_escaped_quotations(q: u64) -> u64 {
     q & !escaped
 }
 
+/// Pairs up consecutive escape bytes from the front of the block (`\\`
+/// escaping a literal backslash), leaving only the escape bytes that aren't
+/// themselves escaped and so really do escape whatever byte follows them.
+const fn active_escapes(e: u64) -> u64 {
+    let mut active = 0u64;
+    let mut bit = 64;
+
+    while bit > 0 {
+        bit -= 1;
+        let mask = 1u64 << bit;
+
+        // bit + 1 is the byte before this one; an escape byte right after
+        // an active escape byte is itself escaped.
+        if e & mask != 0 && (active >> 1) & mask == 0 {
+            active |= mask;
+        }
+    }
+
+    active
+}
+
+/// For dialects using a backslash-style escape (`\"`) instead of doubled
+/// quotes, masks out any quote bit immediately preceded by an unescaped
+/// escape byte. `carry` holds whether the previous block's last byte was an
+/// unescaped escape byte, so a `\` at the very end of one block still
+/// escapes a quote at the start of the next; the returned bool is the carry
+/// for the following block.
+const fn mask_backslash_escaped_quotes_with_carry(
+    quote: u64,
+    escape: u64,
+    carry: bool,
+) -> (u64, bool) {
+    let active = active_escapes(escape);
+
+    // An escape byte at bit position p precedes the byte at bit position
+    // p - 1 (bit 63 is the block's first byte), so shift its bits down by
+    // one to line them up with the quote bits they escape.
+    let mut escaped = quote & (active >> 1);
+
+    if carry && (quote & (1 << 63)) != 0 {
+        escaped |= 1 << 63;
+    }
+
+    let next_carry = active & 1 != 0;
+
+    (quote & !escaped, next_carry)
+}
+
 /// `mark_inside_quotations` does a parallel xor to mark all bits inbetween a quote pair.
 /// Note because of how xor works, the closing quote will be marked as 0. This is fine since
 /// we use this to mask commas and new_line in between quote pairs.
@@ -134,12 +375,233 @@ fn build_u64(chunks: &[u8x16], broadcast: u8x16) -> u64 {
     packed
 }
 
+/// Like [`remove_escaped_quotations`], but carries a trailing-lone-quote bit
+/// across 64-bit blocks so a `""` escape split by the block boundary is
+/// still recognized. `carry` holds whether the previous block ended on an
+/// unescaped quote; it's updated in place for the next block.
+const fn remove_escaped_quotations_with_carry(q: u64, carry: bool) -> (u64, bool) {
+    let mut valid = remove_escaped_quotations(q);
+
+    // The block's first byte is the MSB (bit 63). If the previous block
+    // ended on a dangling quote, this byte being a quote too completes a
+    // `""` pair that spans the boundary, so it's an escaped literal.
+    if carry && (q & (1 << 63)) != 0 {
+        valid &= !(1 << 63);
+    }
+
+    let next_carry = valid & 1 != 0;
+
+    (valid, next_carry)
+}
+
+/// A [`CsvReader`] that consumes successive byte chunks instead of one
+/// contiguous buffer. Quote state -- including a quoted field or a `""`
+/// escape that straddles a chunk boundary -- carries forward between
+/// [`Self::feed`] calls via [`mark_inside_quotations`]'s prefix-xor trick:
+/// each block's in-quote mask is inverted when the "still inside" state
+/// carried from the previous block differs from the parity of unescaped
+/// quotes in this block, and that parity also decides whether the "still
+/// inside" state flips for the next one.
+pub struct StreamingCsvReader {
+    hi_lookup: u8x16,
+    lo_lookup: u8x16,
+    delimiter_broadcast: u8x16,
+    new_line_broadcast: u8x16,
+    quotation_broadcast: u8x16,
+
+    buffer: Vec<u8>,
+    processed: usize,
+
+    quote_escape_carry: bool,
+    quote_inside_carry: bool,
+
+    rows: Vec<RowRef>,
+    current_row: RowRef,
+    start: usize,
+    end: usize,
+}
+
+impl StreamingCsvReader {
+    pub fn new(dialect: Dialect) -> Self {
+        let (lo_lookup, hi_lookup) = crate::classifier::build_lookup_tables(&dialect);
+
+        Self {
+            hi_lookup: u8x16::from_slice_unchecked(&hi_lookup),
+            lo_lookup: u8x16::from_slice_unchecked(&lo_lookup),
+            delimiter_broadcast: u8x16::broadcast(DELIMITER_CLASS),
+            new_line_broadcast: u8x16::broadcast(NEW_LINE_CLASS),
+            quotation_broadcast: u8x16::broadcast(QUOTATION_CLASS),
+            buffer: Vec::new(),
+            processed: 0,
+            quote_escape_carry: false,
+            quote_inside_carry: false,
+            rows: Vec::new(),
+            current_row: Vec::new(),
+            start: 0,
+            end: 0,
+        }
+    }
+
+    /// The bytes fed so far, for resolving the [`FieldRef`]s this reader hands back.
+    pub fn buffer(&self) -> &[u8] {
+        &self.buffer
+    }
+
+    /// Feeds the next chunk and returns any rows that are now fully closed.
+    /// A row left open by a quoted field or a trailing partial line is kept
+    /// internally and completed on a later call (or by [`Self::finish`]).
+    pub fn feed(&mut self, chunk: &[u8]) -> Vec<RowRef> {
+        self.buffer.extend_from_slice(chunk);
+
+        while self.buffer.len() - self.processed >= 64 {
+            let block_start = self.processed;
+            let block = self.buffer[block_start..block_start + 64].to_vec();
+            self.process_block(block_start, &block);
+            self.processed += 64;
+        }
+
+        core::mem::take(&mut self.rows)
+    }
+
+    /// Signals end of input: pads the buffered tail the same way a final
+    /// [`CsvClassifier`](crate::classifier::CsvClassifier) block does, closes
+    /// out a trailing row that wasn't newline-terminated, and returns
+    /// everything that's left.
+    pub fn finish(mut self) -> Vec<RowRef> {
+        let remaining = self.buffer.len() - self.processed;
+
+        if remaining > 0 {
+            let mut block = [0u8; 64];
+            block[..remaining].copy_from_slice(&self.buffer[self.processed..]);
+
+            let last = self.buffer[self.buffer.len() - 1];
+            if last != 0x0A && last != 0x0D {
+                block[remaining] = 0x0A;
+            }
+
+            let block_start = self.processed;
+            self.process_block(block_start, &block);
+            self.processed = self.buffer.len();
+
+            // `process_block` may have advanced `end` into the synthetic
+            // padding past the real data (e.g. no terminator was found in
+            // the padded tail); that padding was never real content, so
+            // clamp back to where the buffer actually ends.
+            self.end = self.end.min(self.buffer.len());
+        }
+
+        if self.start < self.end {
+            self.current_row.push(Range {
+                start: self.start,
+                end: self.end,
+            });
+        }
+
+        if !self.current_row.is_empty() {
+            self.rows.push(core::mem::take(&mut self.current_row));
+        }
+
+        self.rows
+    }
+
+    fn process_block(&mut self, block_start: usize, block: &[u8]) {
+        let mut lanes = [u8x16::broadcast(0); 4];
+        for (i, lane) in lanes.iter_mut().enumerate() {
+            let v = u8x16::from_slice_unchecked(&block[i * 16..i * 16 + 16]);
+            let (hi, lo) = v.nibbles();
+            *lane = self.hi_lookup.classify(hi) & self.lo_lookup.classify(lo);
+        }
+
+        let quote_bits = build_u64(&lanes, self.quotation_broadcast);
+        let delimiter_bits = build_u64(&lanes, self.delimiter_broadcast);
+        let new_line_bits = build_u64(&lanes, self.new_line_broadcast);
+
+        let (valid_quotations, escape_carry) =
+            remove_escaped_quotations_with_carry(quote_bits, self.quote_escape_carry);
+        self.quote_escape_carry = escape_carry;
+
+        // If the carry just dropped this block's leading quote as the second
+        // half of a `""` split by the boundary, the first half (the previous
+        // block's last byte) has already flipped `quote_inside_carry`; flip
+        // it back so the pair as a whole leaves the quote state unchanged.
+        if remove_escaped_quotations(quote_bits) & !valid_quotations & (1 << 63) != 0 {
+            self.quote_inside_carry = !self.quote_inside_carry;
+        }
+
+        // `mark_inside_quotations` is only exact in isolation when this
+        // block's own quote count is even -- an odd count means every bit it
+        // produced is measuring "quotes after" rather than "quotes before",
+        // which flips relative to the carried-in state. So the mask needs
+        // flipping whenever the incoming state and this block's own parity
+        // disagree, not just whenever we carried in "inside".
+        let block_parity = valid_quotations.count_ones() % 2 == 1;
+
+        let mut inside = mark_inside_quotations(valid_quotations);
+        if self.quote_inside_carry ^ block_parity {
+            inside ^= u64::MAX;
+        }
+        self.quote_inside_carry ^= block_parity;
+
+        let outside_quotations = !inside;
+
+        let mut valid_delimiters = delimiter_bits & outside_quotations;
+        let mut valid_new_line = new_line_bits & outside_quotations;
+
+        if valid_delimiters == 0 && valid_new_line == 0 {
+            self.end = block_start + 64;
+            return;
+        }
+
+        loop {
+            let first_delimiter = valid_delimiters.leading_zeros() as usize;
+            let first_new_line = valid_new_line.leading_zeros() as usize;
+
+            let bits_traveled = first_delimiter.min(first_new_line);
+
+            if bits_traveled == 64 {
+                self.end = block_start + 64;
+                break;
+            }
+
+            self.end += bits_traveled;
+
+            let is_newline = first_new_line < first_delimiter;
+            // See `CsvReader::read` for why a CRLF's second half is skipped
+            // but every other zero-width gap is recorded as an empty field.
+            let is_crlf_tail = self.end == self.start
+                && is_newline
+                && self.end > 0
+                && self.end < self.buffer.len()
+                && self.buffer[self.end] == b'\n'
+                && self.buffer[self.end - 1] == b'\r';
+
+            if !is_crlf_tail {
+                self.current_row.push(Range {
+                    start: self.start,
+                    end: self.end,
+                });
+
+                if is_newline {
+                    self.rows.push(core::mem::take(&mut self.current_row));
+                }
+            }
+
+            self.end += 1;
+
+            valid_delimiters <<= bits_traveled + 1;
+            valid_new_line <<= bits_traveled + 1;
+
+            self.start = self.end;
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     fn parse_rows(test: &[u8], expected: Vec<Vec<String>>) {
-        let mut reader = CsvReader::new(test);
+        let mut reader = CsvReader::new(test, Dialect::default());
         let rows = reader
             .read()
             .iter()
@@ -181,6 +643,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn read_exact_lane_multiple_without_trailing_newline() {
+        // 16 bytes, no terminator: the classifier doesn't synthesize a
+        // trailing newline for a tail that exactly fills a 16-byte lane, so
+        // the last field has to be flushed after the main scan loop.
+        let data = b"aaaaaaaaaaaaaa,b";
+        assert_eq!(data.len(), 16);
+
+        parse_rows(
+            data,
+            vec![vec!["aaaaaaaaaaaaaa".to_string(), "b".to_string()]],
+        );
+    }
+
+    #[test]
+    fn read_multiple_rows_exact_lane_multiple_without_trailing_newline() {
+        let data = b"row1a,row1b\naaaa";
+        assert_eq!(data.len(), 16);
+
+        parse_rows(
+            data,
+            vec![
+                vec!["row1a".to_string(), "row1b".to_string()],
+                vec!["aaaa".to_string()],
+            ],
+        );
+    }
+
     #[test]
     fn read_nested() {
         let data = b"\"aaa,howdy\",\"b\"\"bb\",\"ccc\"";
@@ -256,6 +746,151 @@ mod tests {
         );
     }
 
+    #[test]
+    fn read_semicolon_dialect() {
+        let data = b"aaa;'bbb';ccc";
+
+        let mut reader = CsvReader::new(data, Dialect::new(b';', b'\''));
+        let rows = reader
+            .read()
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|range| String::from_utf8(data[range.clone()].to_vec()).unwrap())
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            rows,
+            vec![vec![
+                "aaa".to_string(),
+                "'bbb'".to_string(),
+                "ccc".to_string(),
+            ]]
+        );
+    }
+
+    #[test]
+    fn read_skips_comment_lines() {
+        let data = b"aaa,bbb\n#this is a comment\nccc,ddd";
+
+        let mut reader = CsvReader::new(data, Dialect::default().with_comment(b'#'));
+        let rows = reader
+            .read()
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|range| String::from_utf8(data[range.clone()].to_vec()).unwrap())
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            rows,
+            vec![
+                vec!["aaa".to_string(), "bbb".to_string()],
+                vec!["ccc".to_string(), "ddd".to_string()],
+            ]
+        );
+    }
+
+    #[test]
+    fn read_backslash_escaped_quote() {
+        let data = br#"aaa,"b\"bb",ccc"#;
+
+        let mut reader = CsvReader::new(data, Dialect::default().with_escape(b'\\'));
+        let rows = reader
+            .read()
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|range| String::from_utf8(data[range.clone()].to_vec()).unwrap())
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            rows,
+            vec![vec![
+                "aaa".to_string(),
+                r#""b\"bb""#.to_string(),
+                "ccc".to_string(),
+            ]]
+        );
+    }
+
+    #[test]
+    fn read_backslash_escape_doesnt_affect_default_dialect() {
+        // without escape mode, `\"` is just a literal backslash followed by
+        // a quote -- the quote still opens/closes a field normally.
+        let data = br#"a,"b\",c"#;
+
+        let mut reader = CsvReader::new(data, Dialect::default());
+        let rows = reader
+            .read()
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|range| String::from_utf8(data[range.clone()].to_vec()).unwrap())
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            rows,
+            vec![vec!["a".to_string(), r#""b\""#.to_string(), "c".to_string(),]]
+        );
+    }
+
+    #[test]
+    fn read_typed_basic() {
+        let data = b"1,3.5,true,hello\n2,,false,\"world\"";
+
+        let mut reader = CsvReader::new(data, Dialect::default());
+        let rows = reader
+            .read_typed(&[Tag::Int, Tag::Float, Tag::Bool, Tag::Str])
+            .unwrap();
+
+        assert_eq!(
+            rows,
+            vec![
+                vec![
+                    Cell::Int(1),
+                    Cell::Float(3.5),
+                    Cell::Bool(true),
+                    Cell::Str(b"hello".to_vec()),
+                ],
+                vec![
+                    Cell::Int(2),
+                    Cell::Null,
+                    Cell::Bool(false),
+                    Cell::Str(b"world".to_vec()),
+                ],
+            ]
+        );
+    }
+
+    #[test]
+    fn read_typed_collapses_doubled_quotes() {
+        let data = b"\"say \"\"hi\"\" ok\"";
+
+        let mut reader = CsvReader::new(data, Dialect::default());
+        let rows = reader.read_typed(&[Tag::Str]).unwrap();
+
+        assert_eq!(rows, vec![vec![Cell::Str(b"say \"hi\" ok".to_vec())]]);
+    }
+
+    #[test]
+    fn read_typed_reports_row_and_column_on_failure() {
+        let data = b"1,notanumber";
+
+        let mut reader = CsvReader::new(data, Dialect::default());
+        let err = reader.read_typed(&[Tag::Int, Tag::Int]).unwrap_err();
+
+        assert_eq!(err, TypedReadError { row: 0, column: 1 });
+    }
+
     //     #[test]
     //     fn read_taxi_zone_lookup() {
     //         let data = r#"
@@ -285,6 +920,106 @@ mod tests {
     //         }
     //     }
 
+    fn parse_streamed(chunks: &[&[u8]], expected: Vec<Vec<String>>) {
+        let whole: Vec<u8> = chunks.iter().flat_map(|c| c.iter().copied()).collect();
+
+        let mut reader = StreamingCsvReader::new(Dialect::default());
+        let mut rows = Vec::new();
+        for chunk in chunks {
+            rows.extend(reader.feed(chunk));
+        }
+        rows.extend(reader.finish());
+
+        let rows: Vec<Vec<String>> = rows
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|range| String::from_utf8(whole[range.clone()].to_vec()).unwrap())
+                    .collect()
+            })
+            .collect();
+
+        assert_eq!(rows, expected);
+    }
+
+    #[test]
+    fn streaming_read_basic() {
+        parse_streamed(
+            &[b"aaa,bbb,ccc"],
+            vec![vec![
+                "aaa".to_string(),
+                "bbb".to_string(),
+                "ccc".to_string(),
+            ]],
+        );
+    }
+
+    #[test]
+    fn streaming_quoted_field_spans_chunk_boundary() {
+        // split right in the middle of a quoted field that itself contains
+        // a comma, so `mark_inside_quotations` alone (no carry) would treat
+        // the trailing comma as a field separator.
+        parse_streamed(
+            &[b"aaa,\"bb", b"b,ccc\",ddd"],
+            vec![vec![
+                "aaa".to_string(),
+                "\"bbb,ccc\"".to_string(),
+                "ddd".to_string(),
+            ]],
+        );
+    }
+
+    #[test]
+    fn streaming_escaped_quote_spans_chunk_boundary() {
+        // the doubled quote `""` in `"a""bb"` falls exactly on the boundary.
+        parse_streamed(
+            &[b"\"a\"", b"\"bb\",ccc"],
+            vec![vec!["\"a\"\"bb\"".to_string(), "ccc".to_string()]],
+        );
+    }
+
+    #[test]
+    fn streaming_no_trailing_newline() {
+        parse_streamed(
+            &[b"aaa,bbb", b",ccc"],
+            vec![vec![
+                "aaa".to_string(),
+                "bbb".to_string(),
+                "ccc".to_string(),
+            ]],
+        );
+    }
+
+    #[test]
+    fn streaming_quote_spans_64_byte_block_boundary() {
+        // the opening quote sits on byte 63, the last byte of the first
+        // 64-bit bitset, so `mark_inside_quotations` on block 0 and block 1
+        // must be stitched together via `quote_inside_carry` for the comma
+        // inside the quotes (now in block 1) to stay masked.
+        let mut data = vec![b'x'; 63];
+        data.extend_from_slice(b"\"a,b\"\n");
+
+        // no delimiter precedes the quote, so the run of `x`s and the quoted
+        // span merge into a single field -- only the newline closes it.
+        let field = String::from_utf8(data[..data.len() - 1].to_vec()).unwrap();
+
+        parse_streamed(&[data.as_slice()], vec![vec![field]]);
+    }
+
+    #[test]
+    fn streaming_matches_non_streaming_reader() {
+        let data = b"\"LocationID\",\"Borough\",\"Zone\",\"service_zone\"\r\n1,\"EWR\"";
+
+        let mut reader = CsvReader::new(data, Dialect::default());
+        let expected = reader.read();
+
+        let mut streaming = StreamingCsvReader::new(Dialect::default());
+        let mut rows = streaming.feed(data);
+        rows.extend(streaming.finish());
+
+        assert_eq!(rows, expected);
+    }
+
     #[test]
     fn test_mark_inside_quotations() {
         let res = mark_inside_quotations(0b10001000);